@@ -0,0 +1,456 @@
+//! RGBA colors with hex/named parsing and HSL conversion.
+
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An 8-bit-per-channel RGBA color. Alpha is straight (not premultiplied).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. The leading `#` is
+    /// optional.
+    pub fn from_hex(hex: &str) -> Result<Self, ParseColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(ParseColorError::InvalidHex(hex.to_string()));
+        }
+        let nibble = |i: usize| {
+            u8::from_str_radix(&digits[i..=i], 16)
+                .map(|v| v * 17)
+                .map_err(|_| ParseColorError::InvalidHex(hex.to_string()))
+        };
+        let byte = |i: usize| {
+            u8::from_str_radix(&digits[i..i + 2], 16)
+                .map_err(|_| ParseColorError::InvalidHex(hex.to_string()))
+        };
+        match digits.len() {
+            3 => Ok(Color::rgb(nibble(0)?, nibble(1)?, nibble(2)?)),
+            4 => Ok(Color::rgba(nibble(0)?, nibble(1)?, nibble(2)?, nibble(3)?)),
+            6 => Ok(Color::rgb(byte(0)?, byte(2)?, byte(4)?)),
+            8 => Ok(Color::rgba(byte(0)?, byte(2)?, byte(4)?, byte(6)?)),
+            _ => Err(ParseColorError::InvalidHex(hex.to_string())),
+        }
+    }
+
+    /// Looks up one of the 148 CSS Color Level 4 named colors, or
+    /// `transparent`, case-insensitively. System colors and `currentcolor`
+    /// are not supported.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, c)| *c)
+    }
+
+    /// Formats as `#rrggbb`, or `#rrggbbaa` when not fully opaque.
+    pub fn to_hex(&self) -> String {
+        if self.a == 255 {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+
+    /// Builds an opaque color from hue in degrees and saturation/lightness in
+    /// `[0, 1]`. Hue wraps; saturation and lightness are clamped.
+    pub fn from_hsl(h: f64, s: f64, l: f64) -> Self {
+        Color::from_hsla(h, s, l, 1.0)
+    }
+
+    /// Like [`Color::from_hsl`], with alpha given as a fraction in `[0, 1]`
+    /// (clamped, then scaled to `0..=255`) rather than the `u8` taken by
+    /// [`Color::rgba`] and [`Color::with_alpha`].
+    ///
+    /// A NaN hue does not panic but produces a grey, with all three channels
+    /// equal.
+    pub fn from_hsla(h: f64, s: f64, l: f64, a: f64) -> Self {
+        let h = h.rem_euclid(360.0) / 360.0;
+        let s = s.clamp(0.0, 1.0);
+        let l = l.clamp(0.0, 1.0);
+
+        let (r, g, b) = if s == 0.0 {
+            (l, l, l)
+        } else {
+            let q = if l < 0.5 {
+                l * (1.0 + s)
+            } else {
+                l + s - l * s
+            };
+            let p = 2.0 * l - q;
+            (
+                hue_to_channel(p, q, h + 1.0 / 3.0),
+                hue_to_channel(p, q, h),
+                hue_to_channel(p, q, h - 1.0 / 3.0),
+            )
+        };
+        Color::rgba(to_byte(r), to_byte(g), to_byte(b), to_byte(a))
+    }
+
+    /// Returns `(hue in degrees, saturation, lightness)`, ignoring alpha.
+    pub fn to_hsl(&self) -> (f64, f64, f64) {
+        let r = f64::from(self.r) / 255.0;
+        let g = f64::from(self.g) / 255.0;
+        let b = f64::from(self.b) / 255.0;
+
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return (0.0, 0.0, l);
+        }
+
+        let s = if l > 0.5 {
+            delta / (2.0 - max - min)
+        } else {
+            delta / (max + min)
+        };
+        let h = if max == r {
+            (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+        } else if max == g {
+            (b - r) / delta + 2.0
+        } else {
+            (r - g) / delta + 4.0
+        };
+        (h * 60.0, s, l)
+    }
+
+    /// Alpha as a fraction in `[0, 1]`.
+    pub fn opacity(&self) -> f64 {
+        f64::from(self.a) / 255.0
+    }
+
+    pub fn with_alpha(self, a: u8) -> Self {
+        Color { a, ..self }
+    }
+}
+
+impl Default for Color {
+    fn default() -> Self {
+        Color::BLACK
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+/// Accepts CSS color names and hex strings. Hex input must start with `#` so
+/// that it cannot be mistaken for a name (`"bad"` is not a color); use
+/// [`Color::from_hex`] for bare digits.
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.starts_with('#') {
+            Color::from_hex(s)
+        } else {
+            Color::from_name(s).ok_or_else(|| ParseColorError::UnknownName(s.to_string()))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    InvalidHex(String),
+    UnknownName(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::InvalidHex(s) => write!(f, "invalid hex color: {s:?}"),
+            ParseColorError::UnknownName(s) => write!(f, "unknown color name: {s:?}"),
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+fn hue_to_channel(p: f64, q: f64, t: f64) -> f64 {
+    let t = t.rem_euclid(1.0);
+    if t < 1.0 / 6.0 {
+        p + (q - p) * 6.0 * t
+    } else if t < 0.5 {
+        q
+    } else if t < 2.0 / 3.0 {
+        p + (q - p) * (2.0 / 3.0 - t) * 6.0
+    } else {
+        p
+    }
+}
+
+fn to_byte(v: f64) -> u8 {
+    (v.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Every named color keyword from CSS Color Module Level 4, plus
+/// `transparent`, sorted by name.
+const NAMED_COLORS: &[(&str, Color)] = &[
+    ("transparent", Color::TRANSPARENT),
+    ("aliceblue", Color::rgb(0xf0, 0xf8, 0xff)),
+    ("antiquewhite", Color::rgb(0xfa, 0xeb, 0xd7)),
+    ("aqua", Color::rgb(0x00, 0xff, 0xff)),
+    ("aquamarine", Color::rgb(0x7f, 0xff, 0xd4)),
+    ("azure", Color::rgb(0xf0, 0xff, 0xff)),
+    ("beige", Color::rgb(0xf5, 0xf5, 0xdc)),
+    ("bisque", Color::rgb(0xff, 0xe4, 0xc4)),
+    ("black", Color::rgb(0x00, 0x00, 0x00)),
+    ("blanchedalmond", Color::rgb(0xff, 0xeb, 0xcd)),
+    ("blue", Color::rgb(0x00, 0x00, 0xff)),
+    ("blueviolet", Color::rgb(0x8a, 0x2b, 0xe2)),
+    ("brown", Color::rgb(0xa5, 0x2a, 0x2a)),
+    ("burlywood", Color::rgb(0xde, 0xb8, 0x87)),
+    ("cadetblue", Color::rgb(0x5f, 0x9e, 0xa0)),
+    ("chartreuse", Color::rgb(0x7f, 0xff, 0x00)),
+    ("chocolate", Color::rgb(0xd2, 0x69, 0x1e)),
+    ("coral", Color::rgb(0xff, 0x7f, 0x50)),
+    ("cornflowerblue", Color::rgb(0x64, 0x95, 0xed)),
+    ("cornsilk", Color::rgb(0xff, 0xf8, 0xdc)),
+    ("crimson", Color::rgb(0xdc, 0x14, 0x3c)),
+    ("cyan", Color::rgb(0x00, 0xff, 0xff)),
+    ("darkblue", Color::rgb(0x00, 0x00, 0x8b)),
+    ("darkcyan", Color::rgb(0x00, 0x8b, 0x8b)),
+    ("darkgoldenrod", Color::rgb(0xb8, 0x86, 0x0b)),
+    ("darkgray", Color::rgb(0xa9, 0xa9, 0xa9)),
+    ("darkgreen", Color::rgb(0x00, 0x64, 0x00)),
+    ("darkgrey", Color::rgb(0xa9, 0xa9, 0xa9)),
+    ("darkkhaki", Color::rgb(0xbd, 0xb7, 0x6b)),
+    ("darkmagenta", Color::rgb(0x8b, 0x00, 0x8b)),
+    ("darkolivegreen", Color::rgb(0x55, 0x6b, 0x2f)),
+    ("darkorange", Color::rgb(0xff, 0x8c, 0x00)),
+    ("darkorchid", Color::rgb(0x99, 0x32, 0xcc)),
+    ("darkred", Color::rgb(0x8b, 0x00, 0x00)),
+    ("darksalmon", Color::rgb(0xe9, 0x96, 0x7a)),
+    ("darkseagreen", Color::rgb(0x8f, 0xbc, 0x8f)),
+    ("darkslateblue", Color::rgb(0x48, 0x3d, 0x8b)),
+    ("darkslategray", Color::rgb(0x2f, 0x4f, 0x4f)),
+    ("darkslategrey", Color::rgb(0x2f, 0x4f, 0x4f)),
+    ("darkturquoise", Color::rgb(0x00, 0xce, 0xd1)),
+    ("darkviolet", Color::rgb(0x94, 0x00, 0xd3)),
+    ("deeppink", Color::rgb(0xff, 0x14, 0x93)),
+    ("deepskyblue", Color::rgb(0x00, 0xbf, 0xff)),
+    ("dimgray", Color::rgb(0x69, 0x69, 0x69)),
+    ("dimgrey", Color::rgb(0x69, 0x69, 0x69)),
+    ("dodgerblue", Color::rgb(0x1e, 0x90, 0xff)),
+    ("firebrick", Color::rgb(0xb2, 0x22, 0x22)),
+    ("floralwhite", Color::rgb(0xff, 0xfa, 0xf0)),
+    ("forestgreen", Color::rgb(0x22, 0x8b, 0x22)),
+    ("fuchsia", Color::rgb(0xff, 0x00, 0xff)),
+    ("gainsboro", Color::rgb(0xdc, 0xdc, 0xdc)),
+    ("ghostwhite", Color::rgb(0xf8, 0xf8, 0xff)),
+    ("gold", Color::rgb(0xff, 0xd7, 0x00)),
+    ("goldenrod", Color::rgb(0xda, 0xa5, 0x20)),
+    ("gray", Color::rgb(0x80, 0x80, 0x80)),
+    ("green", Color::rgb(0x00, 0x80, 0x00)),
+    ("greenyellow", Color::rgb(0xad, 0xff, 0x2f)),
+    ("grey", Color::rgb(0x80, 0x80, 0x80)),
+    ("honeydew", Color::rgb(0xf0, 0xff, 0xf0)),
+    ("hotpink", Color::rgb(0xff, 0x69, 0xb4)),
+    ("indianred", Color::rgb(0xcd, 0x5c, 0x5c)),
+    ("indigo", Color::rgb(0x4b, 0x00, 0x82)),
+    ("ivory", Color::rgb(0xff, 0xff, 0xf0)),
+    ("khaki", Color::rgb(0xf0, 0xe6, 0x8c)),
+    ("lavender", Color::rgb(0xe6, 0xe6, 0xfa)),
+    ("lavenderblush", Color::rgb(0xff, 0xf0, 0xf5)),
+    ("lawngreen", Color::rgb(0x7c, 0xfc, 0x00)),
+    ("lemonchiffon", Color::rgb(0xff, 0xfa, 0xcd)),
+    ("lightblue", Color::rgb(0xad, 0xd8, 0xe6)),
+    ("lightcoral", Color::rgb(0xf0, 0x80, 0x80)),
+    ("lightcyan", Color::rgb(0xe0, 0xff, 0xff)),
+    ("lightgoldenrodyellow", Color::rgb(0xfa, 0xfa, 0xd2)),
+    ("lightgray", Color::rgb(0xd3, 0xd3, 0xd3)),
+    ("lightgreen", Color::rgb(0x90, 0xee, 0x90)),
+    ("lightgrey", Color::rgb(0xd3, 0xd3, 0xd3)),
+    ("lightpink", Color::rgb(0xff, 0xb6, 0xc1)),
+    ("lightsalmon", Color::rgb(0xff, 0xa0, 0x7a)),
+    ("lightseagreen", Color::rgb(0x20, 0xb2, 0xaa)),
+    ("lightskyblue", Color::rgb(0x87, 0xce, 0xfa)),
+    ("lightslategray", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightslategrey", Color::rgb(0x77, 0x88, 0x99)),
+    ("lightsteelblue", Color::rgb(0xb0, 0xc4, 0xde)),
+    ("lightyellow", Color::rgb(0xff, 0xff, 0xe0)),
+    ("lime", Color::rgb(0x00, 0xff, 0x00)),
+    ("limegreen", Color::rgb(0x32, 0xcd, 0x32)),
+    ("linen", Color::rgb(0xfa, 0xf0, 0xe6)),
+    ("magenta", Color::rgb(0xff, 0x00, 0xff)),
+    ("maroon", Color::rgb(0x80, 0x00, 0x00)),
+    ("mediumaquamarine", Color::rgb(0x66, 0xcd, 0xaa)),
+    ("mediumblue", Color::rgb(0x00, 0x00, 0xcd)),
+    ("mediumorchid", Color::rgb(0xba, 0x55, 0xd3)),
+    ("mediumpurple", Color::rgb(0x93, 0x70, 0xdb)),
+    ("mediumseagreen", Color::rgb(0x3c, 0xb3, 0x71)),
+    ("mediumslateblue", Color::rgb(0x7b, 0x68, 0xee)),
+    ("mediumspringgreen", Color::rgb(0x00, 0xfa, 0x9a)),
+    ("mediumturquoise", Color::rgb(0x48, 0xd1, 0xcc)),
+    ("mediumvioletred", Color::rgb(0xc7, 0x15, 0x85)),
+    ("midnightblue", Color::rgb(0x19, 0x19, 0x70)),
+    ("mintcream", Color::rgb(0xf5, 0xff, 0xfa)),
+    ("mistyrose", Color::rgb(0xff, 0xe4, 0xe1)),
+    ("moccasin", Color::rgb(0xff, 0xe4, 0xb5)),
+    ("navajowhite", Color::rgb(0xff, 0xde, 0xad)),
+    ("navy", Color::rgb(0x00, 0x00, 0x80)),
+    ("oldlace", Color::rgb(0xfd, 0xf5, 0xe6)),
+    ("olive", Color::rgb(0x80, 0x80, 0x00)),
+    ("olivedrab", Color::rgb(0x6b, 0x8e, 0x23)),
+    ("orange", Color::rgb(0xff, 0xa5, 0x00)),
+    ("orangered", Color::rgb(0xff, 0x45, 0x00)),
+    ("orchid", Color::rgb(0xda, 0x70, 0xd6)),
+    ("palegoldenrod", Color::rgb(0xee, 0xe8, 0xaa)),
+    ("palegreen", Color::rgb(0x98, 0xfb, 0x98)),
+    ("paleturquoise", Color::rgb(0xaf, 0xee, 0xee)),
+    ("palevioletred", Color::rgb(0xdb, 0x70, 0x93)),
+    ("papayawhip", Color::rgb(0xff, 0xef, 0xd5)),
+    ("peachpuff", Color::rgb(0xff, 0xda, 0xb9)),
+    ("peru", Color::rgb(0xcd, 0x85, 0x3f)),
+    ("pink", Color::rgb(0xff, 0xc0, 0xcb)),
+    ("plum", Color::rgb(0xdd, 0xa0, 0xdd)),
+    ("powderblue", Color::rgb(0xb0, 0xe0, 0xe6)),
+    ("purple", Color::rgb(0x80, 0x00, 0x80)),
+    ("rebeccapurple", Color::rgb(0x66, 0x33, 0x99)),
+    ("red", Color::rgb(0xff, 0x00, 0x00)),
+    ("rosybrown", Color::rgb(0xbc, 0x8f, 0x8f)),
+    ("royalblue", Color::rgb(0x41, 0x69, 0xe1)),
+    ("saddlebrown", Color::rgb(0x8b, 0x45, 0x13)),
+    ("salmon", Color::rgb(0xfa, 0x80, 0x72)),
+    ("sandybrown", Color::rgb(0xf4, 0xa4, 0x60)),
+    ("seagreen", Color::rgb(0x2e, 0x8b, 0x57)),
+    ("seashell", Color::rgb(0xff, 0xf5, 0xee)),
+    ("sienna", Color::rgb(0xa0, 0x52, 0x2d)),
+    ("silver", Color::rgb(0xc0, 0xc0, 0xc0)),
+    ("skyblue", Color::rgb(0x87, 0xce, 0xeb)),
+    ("slateblue", Color::rgb(0x6a, 0x5a, 0xcd)),
+    ("slategray", Color::rgb(0x70, 0x80, 0x90)),
+    ("slategrey", Color::rgb(0x70, 0x80, 0x90)),
+    ("snow", Color::rgb(0xff, 0xfa, 0xfa)),
+    ("springgreen", Color::rgb(0x00, 0xff, 0x7f)),
+    ("steelblue", Color::rgb(0x46, 0x82, 0xb4)),
+    ("tan", Color::rgb(0xd2, 0xb4, 0x8c)),
+    ("teal", Color::rgb(0x00, 0x80, 0x80)),
+    ("thistle", Color::rgb(0xd8, 0xbf, 0xd8)),
+    ("tomato", Color::rgb(0xff, 0x63, 0x47)),
+    ("turquoise", Color::rgb(0x40, 0xe0, 0xd0)),
+    ("violet", Color::rgb(0xee, 0x82, 0xee)),
+    ("wheat", Color::rgb(0xf5, 0xde, 0xb3)),
+    ("white", Color::rgb(0xff, 0xff, 0xff)),
+    ("whitesmoke", Color::rgb(0xf5, 0xf5, 0xf5)),
+    ("yellow", Color::rgb(0xff, 0xff, 0x00)),
+    ("yellowgreen", Color::rgb(0x9a, 0xcd, 0x32)),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_hex_length() {
+        assert_eq!(Color::from_hex("#f80"), Ok(Color::rgb(255, 136, 0)));
+        assert_eq!(Color::from_hex("#f80c"), Ok(Color::rgba(255, 136, 0, 204)));
+        assert_eq!(Color::from_hex("#ff8800"), Ok(Color::rgb(255, 136, 0)));
+        assert_eq!(
+            Color::from_hex("#ff8800cc"),
+            Ok(Color::rgba(255, 136, 0, 204))
+        );
+        assert_eq!("#ff8800cc".parse(), Ok(Color::rgba(255, 136, 0, 204)));
+        assert_eq!(Color::from_hex("ff8800"), Ok(Color::rgb(255, 136, 0)));
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        for bad in ["#", "#12345", "#ggg", "#+f+f+f"] {
+            assert_eq!(
+                bad.parse::<Color>(),
+                Err(ParseColorError::InvalidHex(bad.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn to_hex_round_trips() {
+        let opaque = Color::rgb(18, 52, 86);
+        assert_eq!(opaque.to_hex(), "#123456");
+        assert_eq!(Color::from_hex(&opaque.to_hex()), Ok(opaque));
+
+        let translucent = opaque.with_alpha(0x78);
+        assert_eq!(translucent.to_hex(), "#12345678");
+        assert_eq!(Color::from_hex(&translucent.to_hex()), Ok(translucent));
+    }
+
+    #[test]
+    fn names_are_case_insensitive() {
+        assert_eq!("RED".parse(), Ok(Color::rgb(255, 0, 0)));
+        assert_eq!("CornflowerBlue".parse(), Ok(Color::rgb(0x64, 0x95, 0xed)));
+        assert_eq!(Color::from_name("Transparent"), Some(Color::TRANSPARENT));
+        assert_eq!(
+            "nope".parse::<Color>(),
+            Err(ParseColorError::UnknownName("nope".to_string()))
+        );
+    }
+
+    #[test]
+    fn bare_hex_needs_a_hash_when_parsed() {
+        assert_eq!(
+            "ff8800".parse::<Color>(),
+            Err(ParseColorError::UnknownName("ff8800".to_string()))
+        );
+    }
+
+    #[test]
+    fn from_hsl_known_values() {
+        assert_eq!(Color::from_hsl(0.0, 1.0, 0.5), Color::rgb(255, 0, 0));
+        assert_eq!(Color::from_hsl(120.0, 1.0, 0.5), Color::rgb(0, 255, 0));
+        assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::rgb(0, 0, 255));
+        assert_eq!(Color::from_hsl(0.0, 0.0, 1.0), Color::WHITE);
+        assert_eq!(Color::from_hsla(0.0, 1.0, 0.5, 0.5).a, 128);
+        assert_eq!(Color::from_hsla(0.0, 1.0, 0.5, 2.0).a, 255);
+    }
+
+    #[test]
+    fn hue_wraps_around() {
+        assert_eq!(Color::from_hsl(480.0, 1.0, 0.5), Color::rgb(0, 255, 0));
+        assert_eq!(Color::from_hsl(-120.0, 1.0, 0.5), Color::rgb(0, 0, 255));
+    }
+
+    #[test]
+    fn nan_hue_gives_grey() {
+        let c = Color::from_hsl(f64::NAN, 0.5, 0.5);
+        assert!(c.r == c.g && c.g == c.b, "{c:?}");
+    }
+
+    #[test]
+    fn hsl_round_trips() {
+        for c in [
+            Color::rgb(255, 136, 0),
+            Color::rgb(18, 52, 86),
+            Color::rgb(0x66, 0x33, 0x99),
+            Color::rgb(200, 200, 200),
+            Color::BLACK,
+            Color::WHITE,
+        ] {
+            let (h, s, l) = c.to_hsl();
+            assert_eq!(Color::from_hsl(h, s, l), c, "hsl({h}, {s}, {l})");
+        }
+    }
+}
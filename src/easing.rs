@@ -0,0 +1,327 @@
+//! Easing curves mapping normalized time `t` in `[0, 1]` to progress.
+//!
+//! Every curve returns `0.0` at `t = 0.0` and `1.0` at `t = 1.0`; inputs
+//! outside the unit interval are clamped. Elastic curves and steep Bézier
+//! control points may overshoot in between.
+
+use std::f64::consts::PI;
+
+/// A named easing curve, usable wherever a curve has to be stored or passed
+/// around as a value.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+    /// CSS-style cubic Bézier with control points `(x1, y1)` and `(x2, y2)`.
+    CubicBezier(CubicBezier),
+}
+
+impl Easing {
+    pub fn apply(&self, t: f64) -> f64 {
+        match self {
+            Easing::Linear => linear(t),
+            Easing::QuadIn => quad_in(t),
+            Easing::QuadOut => quad_out(t),
+            Easing::QuadInOut => quad_in_out(t),
+            Easing::CubicIn => cubic_in(t),
+            Easing::CubicOut => cubic_out(t),
+            Easing::CubicInOut => cubic_in_out(t),
+            Easing::ElasticIn => elastic_in(t),
+            Easing::ElasticOut => elastic_out(t),
+            Easing::ElasticInOut => elastic_in_out(t),
+            Easing::BounceIn => bounce_in(t),
+            Easing::BounceOut => bounce_out(t),
+            Easing::BounceInOut => bounce_in_out(t),
+            Easing::CubicBezier(curve) => curve.apply(t),
+        }
+    }
+}
+
+fn clamp(t: f64) -> f64 {
+    t.clamp(0.0, 1.0)
+}
+
+pub fn linear(t: f64) -> f64 {
+    clamp(t)
+}
+
+pub fn quad_in(t: f64) -> f64 {
+    let t = clamp(t);
+    t * t
+}
+
+pub fn quad_out(t: f64) -> f64 {
+    let t = clamp(t);
+    1.0 - (1.0 - t) * (1.0 - t)
+}
+
+pub fn quad_in_out(t: f64) -> f64 {
+    let t = clamp(t);
+    if t < 0.5 {
+        2.0 * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(2) / 2.0
+    }
+}
+
+pub fn cubic_in(t: f64) -> f64 {
+    let t = clamp(t);
+    t * t * t
+}
+
+pub fn cubic_out(t: f64) -> f64 {
+    let t = clamp(t);
+    1.0 - (1.0 - t).powi(3)
+}
+
+pub fn cubic_in_out(t: f64) -> f64 {
+    let t = clamp(t);
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+const ELASTIC_PERIOD: f64 = 2.0 * PI / 3.0;
+const ELASTIC_PERIOD_IN_OUT: f64 = 2.0 * PI / 4.5;
+
+pub fn elastic_in(t: f64) -> f64 {
+    let t = clamp(t);
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    -(2.0_f64.powf(10.0 * t - 10.0)) * ((t * 10.0 - 10.75) * ELASTIC_PERIOD).sin()
+}
+
+pub fn elastic_out(t: f64) -> f64 {
+    let t = clamp(t);
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    2.0_f64.powf(-10.0 * t) * ((t * 10.0 - 0.75) * ELASTIC_PERIOD).sin() + 1.0
+}
+
+pub fn elastic_in_out(t: f64) -> f64 {
+    let t = clamp(t);
+    if t == 0.0 || t == 1.0 {
+        return t;
+    }
+    let wave = ((20.0 * t - 11.125) * ELASTIC_PERIOD_IN_OUT).sin();
+    if t < 0.5 {
+        -(2.0_f64.powf(20.0 * t - 10.0) * wave) / 2.0
+    } else {
+        2.0_f64.powf(-20.0 * t + 10.0) * wave / 2.0 + 1.0
+    }
+}
+
+pub fn bounce_out(t: f64) -> f64 {
+    const N: f64 = 7.5625;
+    const D: f64 = 2.75;
+    let t = clamp(t);
+    if t < 1.0 / D {
+        N * t * t
+    } else if t < 2.0 / D {
+        let t = t - 1.5 / D;
+        N * t * t + 0.75
+    } else if t < 2.5 / D {
+        let t = t - 2.25 / D;
+        N * t * t + 0.9375
+    } else {
+        let t = t - 2.625 / D;
+        N * t * t + 0.984375
+    }
+}
+
+pub fn bounce_in(t: f64) -> f64 {
+    1.0 - bounce_out(1.0 - clamp(t))
+}
+
+pub fn bounce_in_out(t: f64) -> f64 {
+    let t = clamp(t);
+    if t < 0.5 {
+        (1.0 - bounce_out(1.0 - 2.0 * t)) / 2.0
+    } else {
+        (1.0 + bounce_out(2.0 * t - 1.0)) / 2.0
+    }
+}
+
+/// Cubic Bézier timing curve anchored at `(0, 0)` and `(1, 1)`, matching the
+/// CSS `cubic-bezier(x1, y1, x2, y2)` function.
+///
+/// Fields are private so that every instance goes through [`CubicBezier::new`]
+/// and keeps `x1` and `x2` inside `[0, 1]`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier {
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+}
+
+impl CubicBezier {
+    /// `x1` and `x2` are clamped to `[0, 1]` so that the curve stays a
+    /// function of time.
+    pub fn new(x1: f64, y1: f64, x2: f64, y2: f64) -> Self {
+        CubicBezier {
+            x1: clamp(x1),
+            y1,
+            x2: clamp(x2),
+            y2,
+        }
+    }
+
+    pub fn x1(&self) -> f64 {
+        self.x1
+    }
+
+    pub fn y1(&self) -> f64 {
+        self.y1
+    }
+
+    pub fn x2(&self) -> f64 {
+        self.x2
+    }
+
+    pub fn y2(&self) -> f64 {
+        self.y2
+    }
+
+    pub fn apply(&self, t: f64) -> f64 {
+        let t = clamp(t);
+        if t == 0.0 || t == 1.0 {
+            return t;
+        }
+        let s = self.solve_x(t);
+        bezier(s, self.y1, self.y2)
+    }
+
+    /// Finds the curve parameter whose x coordinate is `x`, using Newton's
+    /// method and falling back to bisection when the slope is too flat.
+    fn solve_x(&self, x: f64) -> f64 {
+        const EPSILON: f64 = 1e-7;
+
+        let mut s = x;
+        for _ in 0..8 {
+            let err = bezier(s, self.x1, self.x2) - x;
+            if err.abs() < EPSILON {
+                return s;
+            }
+            let slope = bezier_slope(s, self.x1, self.x2);
+            if slope.abs() < 1e-6 {
+                break;
+            }
+            s -= err / slope;
+        }
+
+        let (mut lo, mut hi) = (0.0, 1.0);
+        s = x;
+        while hi - lo > EPSILON {
+            if bezier(s, self.x1, self.x2) < x {
+                lo = s;
+            } else {
+                hi = s;
+            }
+            s = (lo + hi) / 2.0;
+        }
+        s
+    }
+}
+
+fn bezier(s: f64, p1: f64, p2: f64) -> f64 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * s * p1 + 3.0 * inv * s * s * p2 + s * s * s
+}
+
+fn bezier_slope(s: f64, p1: f64, p2: f64) -> f64 {
+    let inv = 1.0 - s;
+    3.0 * inv * inv * p1 + 6.0 * inv * s * (p2 - p1) + 3.0 * s * s * (1.0 - p2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPS: f64 = 1e-9;
+
+    fn all_variants() -> Vec<Easing> {
+        vec![
+            Easing::Linear,
+            Easing::QuadIn,
+            Easing::QuadOut,
+            Easing::QuadInOut,
+            Easing::CubicIn,
+            Easing::CubicOut,
+            Easing::CubicInOut,
+            Easing::ElasticIn,
+            Easing::ElasticOut,
+            Easing::ElasticInOut,
+            Easing::BounceIn,
+            Easing::BounceOut,
+            Easing::BounceInOut,
+            Easing::CubicBezier(CubicBezier::new(0.25, 0.1, 0.25, 1.0)),
+        ]
+    }
+
+    #[test]
+    fn every_curve_hits_its_endpoints() {
+        for easing in all_variants() {
+            assert!(easing.apply(0.0).abs() < EPS, "{easing:?} at 0");
+            assert!((easing.apply(1.0) - 1.0).abs() < EPS, "{easing:?} at 1");
+        }
+    }
+
+    #[test]
+    fn out_of_range_time_is_clamped() {
+        assert_eq!(quad_in(-1.0), 0.0);
+        assert_eq!(cubic_out(2.0), 1.0);
+        for easing in all_variants() {
+            assert!(easing.apply(-5.0).abs() < EPS, "{easing:?} below 0");
+            assert!((easing.apply(5.0) - 1.0).abs() < EPS, "{easing:?} above 1");
+        }
+    }
+
+    #[test]
+    fn cubic_bezier_matches_css_ease() {
+        let ease = CubicBezier::new(0.25, 0.1, 0.25, 1.0);
+        assert!((ease.apply(0.5) - 0.8024).abs() < 1e-4);
+    }
+
+    #[test]
+    fn cubic_bezier_clamps_x_controls() {
+        let curve = CubicBezier::new(-1.0, 0.5, 2.0, 0.5);
+        assert_eq!(curve.x1(), 0.0);
+        assert_eq!(curve.x2(), 1.0);
+        assert_eq!(curve.y1(), 0.5);
+        assert_eq!(curve.y2(), 0.5);
+    }
+
+    #[test]
+    fn cubic_bezier_solves_through_flat_slope() {
+        // x(s) = 3s^2 - 2s^3 has zero slope at s = 0, so Newton bails out for
+        // tiny t and the bisection fallback has to find the root.
+        let curve = CubicBezier::new(0.0, 0.0, 1.0, 1.0);
+        let t = 1.5e-7;
+        assert!(bezier_slope(t, curve.x1, curve.x2) < 1e-6);
+        let s = curve.solve_x(t);
+        assert!((bezier(s, curve.x1, curve.x2) - t).abs() < 1e-9);
+
+        // With matching x and y controls the curve is the identity.
+        for step in 0..=100 {
+            let t = step as f64 / 100.0;
+            assert!((curve.apply(t) - t).abs() < 1e-6, "t = {t}");
+        }
+    }
+}
@@ -0,0 +1,8 @@
+pub mod color;
+pub mod easing;
+
+/// Re-exports of the commonly used types, for `use tech_test::prelude::*;`.
+pub mod prelude {
+    pub use crate::color::{Color, ParseColorError};
+    pub use crate::easing::{CubicBezier, Easing};
+}
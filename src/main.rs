@@ -1,3 +1,18 @@
-fn main() {
-    println!("Hello, world!");
+use tech_test::prelude::*;
+
+fn main() -> Result<(), ParseColorError> {
+    let accent: Color = "#ff8800".parse()?;
+    let (h, s, l) = accent.to_hsl();
+    println!(
+        "{accent} = hsl({h:.0}, {:.0}%, {:.0}%)",
+        s * 100.0,
+        l * 100.0
+    );
+
+    let ease = Easing::CubicBezier(CubicBezier::new(0.25, 0.1, 0.25, 1.0));
+    for step in 0..=4 {
+        let t = f64::from(step) / 4.0;
+        println!("ease({t:.2}) = {:.3}", ease.apply(t));
+    }
+    Ok(())
 }